@@ -0,0 +1,118 @@
+//! Audit policy configuration, loaded from an optional `drift.toml`.
+//!
+//! Every threshold and list used by the checks - stale extensions,
+//! dead-code markers, scanned source extensions, the `.gitignore` skip
+//! allow-list, and each category's severity - used to be hard-coded.
+//! `Config` lets a project extend the stale extensions, dead-code markers
+//! and `.gitignore` allow-list, remap any category's severity (including
+//! downgrading it to `"ignore"` to suppress it), and restrict which checks
+//! run at all. The scanned source extensions are not configurable.
+
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+/// The raw shape of `drift.toml`. Every field is additive or an override on
+/// top of [`Config::default`] - there's no way to express "no markers at
+/// all" short of disabling the check via `disabled_checks`.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "kebab-case")]
+struct RawConfig {
+    extra_stale_extensions: Vec<String>,
+    extra_dead_code_markers: Vec<String>,
+    extra_gitignore_allow_list: Vec<String>,
+    severities: HashMap<String, String>,
+    disabled_checks: Vec<String>,
+    allowed_licenses: Vec<String>,
+    crate_allow_list: Vec<String>,
+}
+
+/// Resolved audit policy, threaded through `run_audit` and every `check_*`
+/// function.
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub stale_extensions: Vec<String>,
+    pub dead_code_markers: Vec<String>,
+    pub source_extensions: Vec<String>,
+    pub gitignore_allow_list: Vec<String>,
+    /// SPDX license identifiers a dependency's `license` field must match at
+    /// least one of. Empty means the license policy isn't enforced.
+    pub allowed_licenses: Vec<String>,
+    /// Crate names allowed as dependencies. Empty means the allow-list isn't
+    /// enforced.
+    pub crate_allow_list: Vec<String>,
+    severities: HashMap<String, String>,
+    disabled_checks: Vec<String>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            stale_extensions: strings(&["old", "bak", "tmp", "swp", "orig"]),
+            dead_code_markers: strings(&["TODO", "FIXME", "XXX", "HACK"]),
+            source_extensions: strings(&["rs", "js", "ts", "py", "go", "java", "c", "cpp", "h"]),
+            gitignore_allow_list: strings(&[
+                "*.log",
+                "*.tmp",
+                ".env",
+                ".env.local",
+                "node_modules",
+                "target",
+                "dist",
+                "build",
+            ]),
+            allowed_licenses: Vec::new(),
+            crate_allow_list: Vec::new(),
+            severities: HashMap::new(),
+            disabled_checks: Vec::new(),
+        }
+    }
+}
+
+fn strings(values: &[&str]) -> Vec<String> {
+    values.iter().map(|s| s.to_string()).collect()
+}
+
+impl Config {
+    /// Loads `drift.toml` from `root`, or from `override_path` when given.
+    /// Falls back to [`Config::default`] when the file is absent or
+    /// malformed, so a missing config never breaks the audit.
+    pub fn load(root: &Path, override_path: Option<&Path>) -> Config {
+        let path = match override_path {
+            Some(path) => path.to_path_buf(),
+            None => root.join("drift.toml"),
+        };
+
+        let Ok(content) = fs::read_to_string(&path) else {
+            return Config::default();
+        };
+        let raw: RawConfig = toml::from_str(&content).unwrap_or_default();
+
+        let mut config = Config::default();
+        config.stale_extensions.extend(raw.extra_stale_extensions);
+        config.dead_code_markers.extend(raw.extra_dead_code_markers);
+        config
+            .gitignore_allow_list
+            .extend(raw.extra_gitignore_allow_list);
+        config.severities = raw.severities;
+        config.disabled_checks = raw.disabled_checks;
+        config.allowed_licenses = raw.allowed_licenses;
+        config.crate_allow_list = raw.crate_allow_list;
+        config
+    }
+
+    /// Resolves the effective severity for `category`, honoring an override
+    /// from `drift.toml` if one was declared.
+    pub fn severity_for(&self, category: &str, original: &str) -> String {
+        self.severities
+            .get(category)
+            .cloned()
+            .unwrap_or_else(|| original.to_string())
+    }
+
+    /// Whether `check_name` is allowed to run at all.
+    pub fn is_check_enabled(&self, check_name: &str) -> bool {
+        !self.disabled_checks.iter().any(|c| c == check_name)
+    }
+}
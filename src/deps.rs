@@ -0,0 +1,136 @@
+//! License and dependency-policy auditing, mirroring what rustc's `tidy`
+//! tool does with its license and crate allow-lists.
+//!
+//! `check_dependency_policy` resolves each dependency's license via `cargo
+//! metadata` and flags any crate whose license falls outside the SPDX
+//! allow-list declared in `drift.toml`, or that isn't on the crate
+//! allow-list when one is enforced. Both lists are opt-in: a `drift.toml`
+//! that declares neither means this check enforces nothing.
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::{Config, Issue};
+
+pub fn check_dependency_policy(root: &Path, config: &Config) -> Vec<Issue> {
+    let mut issues = Vec::new();
+
+    if config.allowed_licenses.is_empty() && config.crate_allow_list.is_empty() {
+        return issues; // no policy declared in drift.toml; nothing to enforce
+    }
+
+    // Deliberately omit --no-deps: we need the full resolved dependency
+    // graph, not just the workspace's own package(s).
+    let Ok(output) = Command::new("cargo")
+        .args(["metadata", "--format-version=1"])
+        .current_dir(root)
+        .output()
+    else {
+        return issues;
+    };
+    if !output.status.success() {
+        return issues;
+    }
+    let Ok(metadata) = serde_json::from_slice::<serde_json::Value>(&output.stdout) else {
+        return issues;
+    };
+    let Some(packages) = metadata.get("packages").and_then(|p| p.as_array()) else {
+        return issues;
+    };
+    let workspace_members: HashSet<&str> = metadata
+        .get("workspace_members")
+        .and_then(|m| m.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|id| id.as_str())
+        .collect();
+
+    for pkg in packages {
+        let Some(id) = pkg.get("id").and_then(|i| i.as_str()) else {
+            continue;
+        };
+        if workspace_members.contains(id) {
+            continue; // audit dependencies, not the workspace's own crates
+        }
+        let Some(name) = pkg.get("name").and_then(|n| n.as_str()) else {
+            continue;
+        };
+        check_crate_allow_list(name, config, &mut issues);
+        check_license(
+            name,
+            pkg.get("license").and_then(|l| l.as_str()),
+            config,
+            &mut issues,
+        );
+    }
+
+    issues
+}
+
+fn check_crate_allow_list(name: &str, config: &Config, issues: &mut Vec<Issue>) {
+    if config.crate_allow_list.is_empty() {
+        return;
+    }
+    if !config.crate_allow_list.iter().any(|c| c == name) {
+        issues.push(Issue {
+            category: "dependency_policy".to_string(),
+            severity: "error".to_string(),
+            message: format!("Crate '{name}' is not on the configured dependency allow-list"),
+            path: Some(PathBuf::from("Cargo.toml")),
+            line: None,
+            suggestion: None,
+        });
+    }
+}
+
+fn check_license(name: &str, license: Option<&str>, config: &Config, issues: &mut Vec<Issue>) {
+    if config.allowed_licenses.is_empty() {
+        return;
+    }
+
+    match license {
+        Some(license) if is_license_allowed(license, &config.allowed_licenses) => {}
+        Some(license) => issues.push(Issue {
+            category: "dependency_policy".to_string(),
+            severity: "error".to_string(),
+            message: format!(
+                "Crate '{name}' has license '{license}', which isn't in the allowed SPDX set"
+            ),
+            path: Some(PathBuf::from("Cargo.toml")),
+            line: None,
+            suggestion: None,
+        }),
+        None => issues.push(Issue {
+            category: "dependency_policy".to_string(),
+            severity: "warning".to_string(),
+            message: format!("Crate '{name}' declares no license"),
+            path: Some(PathBuf::from("Cargo.toml")),
+            line: None,
+            suggestion: None,
+        }),
+    }
+}
+
+/// SPDX `license` fields can be compound expressions like `"MIT OR
+/// Apache-2.0"`, `"(Apache-2.0 WITH LLVM-exception)"`, or `"MIT AND
+/// GPL-3.0-only"` (a dual-licensed dependency where *both* terms actually
+/// apply). `OR` is satisfied by any one allowed term, but `AND` requires
+/// every term in that conjunction to be allowed - treating it the same as
+/// `OR` would let a disallowed copyleft license ride along next to an
+/// allowed one. This doesn't implement full SPDX expression precedence (no
+/// nested parens across mixed AND/OR), just the flat token stream `cargo
+/// metadata` actually emits in practice.
+fn is_license_allowed(license_expr: &str, allowed: &[String]) -> bool {
+    let normalized = license_expr.replace(['(', ')', '/'], " ");
+    let tokens: Vec<&str> = normalized.split_whitespace().collect();
+
+    tokens
+        .split(|t| t.eq_ignore_ascii_case("AND"))
+        .all(|conjunct| {
+            conjunct
+                .iter()
+                .filter(|t| !t.eq_ignore_ascii_case("or") && !t.eq_ignore_ascii_case("with"))
+                .any(|t| allowed.iter().any(|a| a == t))
+        })
+}
@@ -0,0 +1,100 @@
+//! `--fix` auto-remediation, modeled on how `rustfix` applies compiler
+//! suggestions: a self-healing `Issue` carries a `Suggestion` describing the
+//! exact bytes to replace, suggestions are collected per file, any that
+//! overlap are dropped rather than risking corruption, and the rest are
+//! applied atomically (write to a temp file, then rename over the original).
+
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+use crate::Issue;
+
+/// A single structured edit: replace `byte_range` in `path` with
+/// `replacement`. A `byte_range` of `0..usize::MAX` with an empty
+/// `replacement` means "delete this file entirely" (see [`delete_file`]).
+#[derive(Debug, Clone)]
+pub struct Suggestion {
+    pub path: PathBuf,
+    pub byte_range: Range<usize>,
+    pub replacement: String,
+}
+
+/// Builds the suggestion for checks whose only remediation is removing the
+/// file outright (e.g. a stale `.old`/`.bak`/`.orig` backup file).
+pub fn delete_file(path: PathBuf) -> Suggestion {
+    Suggestion {
+        path,
+        byte_range: 0..usize::MAX,
+        replacement: String::new(),
+    }
+}
+
+/// Applies every non-overlapping suggestion attached to `issues`, grouped by
+/// file, and returns the paths that were changed.
+pub fn apply(issues: &[Issue]) -> io::Result<Vec<PathBuf>> {
+    let mut by_file: HashMap<PathBuf, Vec<&Suggestion>> = HashMap::new();
+    for issue in issues {
+        if let Some(suggestion) = &issue.suggestion {
+            by_file
+                .entry(suggestion.path.clone())
+                .or_default()
+                .push(suggestion);
+        }
+    }
+
+    let mut changed = Vec::new();
+    for (path, suggestions) in by_file {
+        let accepted = drop_overlapping(suggestions);
+        if accepted.is_empty() {
+            continue;
+        }
+        apply_to_file(&path, &accepted)?;
+        changed.push(path);
+    }
+
+    changed.sort();
+    Ok(changed)
+}
+
+/// Sorts suggestions by start offset and drops any whose range overlaps a
+/// suggestion that was already accepted.
+fn drop_overlapping(mut suggestions: Vec<&Suggestion>) -> Vec<&Suggestion> {
+    suggestions.sort_by_key(|s| s.byte_range.start);
+    let mut accepted: Vec<&Suggestion> = Vec::new();
+    for suggestion in suggestions {
+        let overlaps = accepted
+            .last()
+            .is_some_and(|prev| suggestion.byte_range.start < prev.byte_range.end);
+        if !overlaps {
+            accepted.push(suggestion);
+        }
+    }
+    accepted
+}
+
+fn apply_to_file(path: &Path, suggestions: &[&Suggestion]) -> io::Result<()> {
+    if suggestions
+        .iter()
+        .any(|s| s.byte_range.end == usize::MAX && s.replacement.is_empty())
+    {
+        return fs::remove_file(path);
+    }
+
+    let content = fs::read(path)?;
+    let mut result = Vec::with_capacity(content.len());
+    let mut cursor = 0;
+    for suggestion in suggestions {
+        result.extend_from_slice(&content[cursor..suggestion.byte_range.start]);
+        result.extend_from_slice(suggestion.replacement.as_bytes());
+        cursor = suggestion.byte_range.end;
+    }
+    result.extend_from_slice(&content[cursor..]);
+
+    let tmp_path = path.with_extension("drift-fix.tmp");
+    fs::write(&tmp_path, &result)?;
+    fs::rename(&tmp_path, path)?;
+    Ok(())
+}
@@ -0,0 +1,182 @@
+//! Git drift detection built on `gix` instead of shelling out to `git`.
+//!
+//! The previous implementation parsed `git status --porcelain` output, which
+//! only distinguished modified from untracked files and silently produced no
+//! issues at all when `git` wasn't on `PATH`. `gix` lets us see richer drift:
+//! how far the local branch has diverged from its upstream, whether `HEAD` is
+//! detached, staged changes distinct from working-tree changes, and
+//! submodules whose checked-out commit doesn't match what's recorded.
+
+use std::path::{Path, PathBuf};
+
+use crate::{Config, Issue};
+
+/// Runs every git-based drift check against the repository rooted at `root`.
+/// Takes `_config` for consistency with the other `check_*` entry points;
+/// `drift.toml` currently has no git-specific settings, but severities and
+/// whether this check runs at all are already applied centrally in
+/// `run_audit`.
+pub fn check_git_drift(root: &Path, _config: &Config) -> Vec<Issue> {
+    let mut issues = Vec::new();
+
+    let repo = match gix::discover(root) {
+        Ok(repo) => repo,
+        Err(_) => return issues, // not a git repository; nothing to report
+    };
+
+    check_head_state(&repo, &mut issues);
+    check_upstream_divergence(&repo, &mut issues);
+    check_working_tree_drift(&repo, &mut issues);
+    check_submodule_drift(&repo, &mut issues);
+
+    issues
+}
+
+fn check_head_state(repo: &gix::Repository, issues: &mut Vec<Issue>) {
+    if repo.head().is_ok_and(|head| head.is_detached()) {
+        issues.push(Issue {
+            category: "git_drift".to_string(),
+            severity: "warning".to_string(),
+            message: "HEAD is detached".to_string(),
+            path: None,
+            line: None,
+            suggestion: None,
+        });
+    }
+}
+
+fn check_upstream_divergence(repo: &gix::Repository, issues: &mut Vec<Issue>) {
+    let Ok(Some(head_name)) = repo.head_name() else {
+        return; // detached HEAD, already reported above
+    };
+    let Ok(Some(upstream)) =
+        repo.branch_remote_ref_name(head_name.as_ref(), gix::remote::Direction::Fetch)
+    else {
+        return; // no configured upstream
+    };
+    let Ok(Some(local_id)) = repo.head_id().map(Some) else {
+        return;
+    };
+    let Ok(upstream_id) = repo
+        .find_reference(upstream.as_ref())
+        .and_then(|r| r.into_fully_peeled_id())
+    else {
+        return;
+    };
+    let Ok(divergence) = repo.ahead_behind(local_id.detach(), upstream_id.detach()) else {
+        return;
+    };
+
+    let branch = head_name.shorten().to_string();
+
+    if divergence.ahead > 0 {
+        issues.push(Issue {
+            category: "git_drift".to_string(),
+            severity: "info".to_string(),
+            message: format!(
+                "Branch '{}' is {} commit(s) ahead of its upstream",
+                branch, divergence.ahead
+            ),
+            path: None,
+            line: None,
+            suggestion: None,
+        });
+    }
+
+    if divergence.behind > 0 {
+        issues.push(Issue {
+            category: "git_drift".to_string(),
+            severity: "warning".to_string(),
+            message: format!(
+                "Branch '{}' is {} commit(s) behind its upstream",
+                branch, divergence.behind
+            ),
+            path: None,
+            line: None,
+            suggestion: None,
+        });
+    }
+}
+
+fn check_working_tree_drift(repo: &gix::Repository, issues: &mut Vec<Issue>) {
+    let Ok(status) = repo.status(gix::progress::Discard) else {
+        return;
+    };
+    let Ok(items) = status.into_iter(None) else {
+        return;
+    };
+
+    let mut staged = 0usize;
+    let mut unstaged = 0usize;
+    for item in items.flatten() {
+        match item {
+            gix::status::Item::TreeIndex(_) => staged += 1,
+            gix::status::Item::IndexWorktree(_) => unstaged += 1,
+        }
+    }
+
+    if staged > 0 {
+        issues.push(Issue {
+            category: "git_drift".to_string(),
+            severity: "warning".to_string(),
+            message: format!("{} file(s) staged but not committed", staged),
+            path: None,
+            line: None,
+            suggestion: None,
+        });
+    }
+
+    if unstaged > 0 {
+        issues.push(Issue {
+            category: "git_drift".to_string(),
+            severity: "info".to_string(),
+            message: format!(
+                "{} file(s) modified or untracked in the working tree",
+                unstaged
+            ),
+            path: None,
+            line: None,
+            suggestion: None,
+        });
+    }
+}
+
+/// Compares each submodule's recorded commit - what the superproject's index
+/// says it should be at - against the commit actually checked out in the
+/// submodule's own working copy. `is_dirty()` answers a different question
+/// (whether the submodule's working tree has uncommitted changes) and misses
+/// the common case of a submodule sitting on a stale-but-clean commit.
+fn check_submodule_drift(repo: &gix::Repository, issues: &mut Vec<Issue>) {
+    let Ok(Some(submodules)) = repo.submodules() else {
+        return;
+    };
+
+    for sm in submodules {
+        let Ok(recorded_id) = sm.id() else {
+            continue;
+        };
+        let Ok(Some(sub_repo)) = sm.open() else {
+            continue; // not checked out; nothing to compare
+        };
+        let Ok(checked_out_id) = sub_repo.head_id() else {
+            continue;
+        };
+
+        if checked_out_id.detach() == recorded_id {
+            continue;
+        }
+
+        let path = sm.path().ok().map(|p| PathBuf::from(p.to_string()));
+        issues.push(Issue {
+            category: "git_drift".to_string(),
+            severity: "warning".to_string(),
+            message: format!(
+                "Submodule '{}' checked-out commit differs from the recorded commit",
+                sm.name()
+            ),
+            path,
+            line: None,
+            suggestion: None,
+        });
+    }
+}
@@ -1,7 +1,17 @@
 use serde::{Deserialize, Serialize};
 use std::fs;
-use std::path::PathBuf;
-use std::process::Command;
+use std::path::{Path, PathBuf};
+
+mod config;
+mod deps;
+mod fix;
+mod git;
+mod lockfile;
+mod version;
+mod walk;
+
+pub use config::Config;
+pub use fix::Suggestion;
 
 /// Represents a single issue found during the drift audit.
 #[derive(Debug, Serialize, Deserialize)]
@@ -11,208 +21,64 @@ pub struct Issue {
     pub message: String,
     pub path: Option<PathBuf>,
     pub line: Option<usize>,
+    /// A structured, self-applicable fix for this issue, if one exists.
+    #[serde(skip)]
+    pub suggestion: Option<Suggestion>,
 }
 
 /// Runs all drift audit checks and returns a list of found issues.
-pub fn run_audit() -> Vec<Issue> {
-    let mut issues = Vec::new();
-
-    issues.extend(check_stale_configs());
-    issues.extend(check_version_mismatches());
-    issues.extend(check_dead_code_markers());
-    issues.extend(check_git_drift());
-    issues.extend(check_gitignore_drift());
+///
+/// Loads `drift.toml` from the current directory, or from `config_path` when
+/// given, to decide which checks run and how their issues are categorized.
+pub fn run_audit(config_path: Option<&Path>) -> Vec<Issue> {
+    let root = Path::new(".");
+    let config = Config::load(root, config_path);
 
-    issues
-}
-
-/// Checks for stale configuration or backup files (e.g., .old, .bak).
-fn check_stale_configs() -> Vec<Issue> {
     let mut issues = Vec::new();
-    let stale_extensions = ["old", "bak", "tmp", "swp", "orig"];
 
-    fn walk_dir(dir: &std::path::Path, extensions: &[&str], issues: &mut Vec<Issue>) {
-        if let Ok(entries) = fs::read_dir(dir) {
-            for entry in entries.filter_map(|e| e.ok()) {
-                let path = entry.path();
-                if path.is_dir() {
-                    if path
-                        .file_name()
-                        .is_some_and(|n| n != "target" && n != ".git")
-                    {
-                        walk_dir(&path, extensions, issues);
-                    }
-                } else if path.is_file() {
-                    if let Some(ext) = path.extension() {
-                        if extensions.iter().any(|&s| ext == s) {
-                            issues.push(Issue {
-                                category: "stale_config".to_string(),
-                                severity: "warning".to_string(),
-                                message: "Stale configuration or backup file".to_string(),
-                                path: Some(path),
-                                line: None,
-                            });
-                        }
-                    }
-                }
-            }
-        }
+    issues.extend(walk::run_file_checks(root, &config));
+    if config.is_check_enabled("version_mismatch") {
+        issues.extend(version::check_version_mismatches(root, &config));
     }
-
-    walk_dir(std::path::Path::new("."), &stale_extensions, &mut issues);
-    issues
-}
-
-/// Checks for version mismatches between toolchain files.
-fn check_version_mismatches() -> Vec<Issue> {
-    let mut issues = Vec::new();
-
-    // Check rust-toolchain.toml vs Cargo.toml rust-version
-    if std::path::Path::new("rust-toolchain.toml").exists() {
-        if let Ok(content) = fs::read_to_string("rust-toolchain.toml") {
-            if content.contains("nightly") {
-                // Check if Cargo.toml has rust-version set (which conflicts with nightly)
-                if let Ok(cargo) = fs::read_to_string("Cargo.toml") {
-                    if cargo.contains("rust-version") {
-                        issues.push(Issue {
-                            category: "version_mismatch".to_string(),
-                            severity: "warning".to_string(),
-                            message: "rust-toolchain.toml uses nightly but Cargo.toml has rust-version set".to_string(),
-                            path: Some(PathBuf::from("rust-toolchain.toml")),
-                            line: None,
-                        });
-                    }
-                }
-            }
-        }
+    if config.is_check_enabled("git_drift") {
+        issues.extend(git::check_git_drift(root, &config));
     }
-
-    // Check for .nvmrc vs package.json engines
-    if std::path::Path::new(".nvmrc").exists() && std::path::Path::new("package.json").exists() {
-        if let (Ok(nvmrc), Ok(pkg)) = (
-            fs::read_to_string(".nvmrc"),
-            fs::read_to_string("package.json"),
-        ) {
-            let nvmrc_version = nvmrc.trim();
-            if !pkg.contains(nvmrc_version) && pkg.contains("\"engines\"") {
-                issues.push(Issue {
-                    category: "version_mismatch".to_string(),
-                    severity: "warning".to_string(),
-                    message: format!(
-                        ".nvmrc specifies {} but package.json engines may differ",
-                        nvmrc_version
-                    ),
-                    path: Some(PathBuf::from(".nvmrc")),
-                    line: None,
-                });
-            }
-        }
+    if config.is_check_enabled("gitignore_drift") {
+        issues.extend(check_gitignore_drift(&config));
     }
-
-    issues
-}
-
-/// Searches for dead code markers like TODO, FIXME in source code.
-fn check_dead_code_markers() -> Vec<Issue> {
-    let mut issues = Vec::new();
-    let markers = ["TODO", "FIXME", "XXX", "HACK"];
-
-    fn scan_file(path: &std::path::Path, markers: &[&str], issues: &mut Vec<Issue>) {
-        if let Ok(content) = fs::read_to_string(path) {
-            for (line_num, line) in content.lines().enumerate() {
-                for marker in markers {
-                    if line.contains(marker) {
-                        issues.push(Issue {
-                            category: "dead_code".to_string(),
-                            severity: "info".to_string(),
-                            message: format!("{} marker found", marker),
-                            path: Some(path.to_path_buf()),
-                            line: Some(line_num + 1),
-                        });
-                    }
-                }
-            }
-        }
+    if config.is_check_enabled("lockfile_drift") {
+        issues.extend(lockfile::check_lockfile_drift(root, &config));
+    }
+    if config.is_check_enabled("dependency_policy") {
+        issues.extend(deps::check_dependency_policy(root, &config));
     }
 
-    fn walk_source(dir: &std::path::Path, markers: &[&str], issues: &mut Vec<Issue>) {
-        if let Ok(entries) = fs::read_dir(dir) {
-            for entry in entries.filter_map(|e| e.ok()) {
-                let path = entry.path();
-                if path.is_dir() {
-                    if path
-                        .file_name()
-                        .is_some_and(|n| n != "target" && n != ".git" && n != "node_modules")
-                    {
-                        walk_source(&path, markers, issues);
-                    }
-                } else if path.is_file() {
-                    let ext = path.extension().and_then(|e| e.to_str()).unwrap_or("");
-                    if matches!(
-                        ext,
-                        "rs" | "js" | "ts" | "py" | "go" | "java" | "c" | "cpp" | "h"
-                    ) {
-                        scan_file(&path, markers, issues);
-                    }
-                }
-            }
-        }
+    for issue in &mut issues {
+        issue.severity = config.severity_for(&issue.category, &issue.severity);
     }
+    issues.retain(|issue| issue.severity != "ignore");
 
-    walk_source(std::path::Path::new("."), &markers, &mut issues);
     issues
 }
 
-/// Checks for uncommitted changes in git.
-fn check_git_drift() -> Vec<Issue> {
-    let mut issues = Vec::new();
-
-    let output = Command::new("git").args(["status", "--porcelain"]).output();
-
-    if let Ok(output) = output {
-        let stdout = String::from_utf8_lossy(&output.stdout);
-        let lines: Vec<&str> = stdout.lines().collect();
-
-        if !lines.is_empty() {
-            let modified = lines
-                .iter()
-                .filter(|l| l.starts_with(" M") || l.starts_with("M "))
-                .count();
-            let untracked = lines.iter().filter(|l| l.starts_with("??")).count();
-
-            if modified > 0 {
-                issues.push(Issue {
-                    category: "git_drift".to_string(),
-                    severity: "warning".to_string(),
-                    message: format!("{} modified files not committed", modified),
-                    path: None,
-                    line: None,
-                });
-            }
-
-            if untracked > 0 {
-                issues.push(Issue {
-                    category: "git_drift".to_string(),
-                    severity: "info".to_string(),
-                    message: format!("{} untracked files", untracked),
-                    path: None,
-                    line: None,
-                });
-            }
-        }
-    }
-
-    issues
+/// Applies every self-healing [`Suggestion`] attached to `issues` and returns
+/// the paths that were changed. Used by `--fix`.
+pub fn apply_fixes(issues: &[Issue]) -> std::io::Result<Vec<PathBuf>> {
+    fix::apply(issues)
 }
 
 /// Checks for entries in .gitignore that don't match any files.
-fn check_gitignore_drift() -> Vec<Issue> {
+fn check_gitignore_drift(config: &Config) -> Vec<Issue> {
     let mut issues = Vec::new();
 
     match fs::read_to_string(".gitignore") {
         Ok(content) => {
-            for line in content.lines() {
-                let line = line.trim();
+            let mut offset = 0;
+            for raw_line in content.split_inclusive('\n') {
+                let line = raw_line.trim_end_matches('\n').trim();
+                let line_range = offset..offset + raw_line.len();
+                offset += raw_line.len();
+
                 if line.is_empty() || line.starts_with('#') {
                     continue;
                 }
@@ -221,18 +87,12 @@ fn check_gitignore_drift() -> Vec<Issue> {
                 if !line.contains('*') && !line.contains('?') {
                     let path = std::path::Path::new(line.trim_start_matches('/'));
                     if !path.exists() && !line.ends_with('/') {
-                        // Skip common patterns that may not exist yet
-                        if !matches!(
-                            line,
-                            "*.log"
-                                | "*.tmp"
-                                | ".env"
-                                | ".env.local"
-                                | "node_modules"
-                                | "target"
-                                | "dist"
-                                | "build"
-                        ) {
+                        // Skip patterns the config allow-lists as not expected to exist yet
+                        if !config
+                            .gitignore_allow_list
+                            .iter()
+                            .any(|allowed| allowed == line)
+                        {
                             issues.push(Issue {
                                 category: "gitignore_drift".to_string(),
                                 severity: "info".to_string(),
@@ -242,6 +102,11 @@ fn check_gitignore_drift() -> Vec<Issue> {
                                 ),
                                 path: Some(PathBuf::from(".gitignore")),
                                 line: None,
+                                suggestion: Some(Suggestion {
+                                    path: PathBuf::from(".gitignore"),
+                                    byte_range: line_range,
+                                    replacement: String::new(),
+                                }),
                             });
                         }
                     }
@@ -258,6 +123,7 @@ fn check_gitignore_drift() -> Vec<Issue> {
                 message: format!("Failed to read .gitignore: {}", e),
                 path: Some(PathBuf::from(".gitignore")),
                 line: None,
+                suggestion: None,
             });
         }
     }
@@ -271,7 +137,7 @@ mod tests {
 
     #[test]
     fn test_run_audit_returns_vec() {
-        let issues = run_audit();
+        let issues = run_audit(None);
         // Verify that any issues found are well-formed
         for issue in &issues {
             assert!(
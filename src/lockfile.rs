@@ -0,0 +1,186 @@
+//! Dependency drift detection by comparing `Cargo.toml` against `Cargo.lock`.
+//!
+//! None of the existing checks look inside `Cargo.lock`, so duplicate
+//! resolved versions of the same crate, dependencies that were added to
+//! `Cargo.toml` but never re-locked, and a lockfile that's simply gone stale
+//! all go unnoticed.
+
+use semver::Version;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::{Config, Issue};
+
+/// Takes `_config` for consistency with the other `check_*` entry points;
+/// `drift.toml` currently has no lockfile-specific settings, but severities
+/// and whether this check runs at all are already applied centrally in
+/// `run_audit`.
+pub fn check_lockfile_drift(root: &Path, _config: &Config) -> Vec<Issue> {
+    let mut issues = Vec::new();
+
+    let lock_path = root.join("Cargo.lock");
+    let manifest_path = root.join("Cargo.toml");
+
+    let Ok(lock_content) = fs::read_to_string(&lock_path) else {
+        return issues; // no lockfile to compare against
+    };
+    let Ok(lock_doc) = lock_content.parse::<toml::Value>() else {
+        return issues;
+    };
+    let packages = lock_doc
+        .get("package")
+        .and_then(|p| p.as_array())
+        .cloned()
+        .unwrap_or_default();
+
+    check_duplicate_majors(&packages, &lock_path, &mut issues);
+    check_missing_from_lock(&manifest_path, &packages, &lock_path, &mut issues);
+    check_lock_mtime(&manifest_path, &lock_path, &mut issues);
+
+    issues
+}
+
+/// Flags crates resolved at more than one semver-incompatible version, e.g.
+/// `curl 0.1.17` and `curl 0.2.0` coexisting in the same lockfile.
+fn check_duplicate_majors(packages: &[toml::Value], lock_path: &Path, issues: &mut Vec<Issue>) {
+    let mut by_name: HashMap<&str, Vec<Version>> = HashMap::new();
+
+    for pkg in packages {
+        let Some(name) = pkg.get("name").and_then(|n| n.as_str()) else {
+            continue;
+        };
+        let Some(version) = pkg
+            .get("version")
+            .and_then(|v| v.as_str())
+            .and_then(|v| Version::parse(v).ok())
+        else {
+            continue;
+        };
+        by_name.entry(name).or_default().push(version);
+    }
+
+    for (name, mut versions) in by_name {
+        versions.sort();
+        let mut buckets: Vec<(u64, u64, u64)> = versions.iter().map(compat_bucket).collect();
+        buckets.dedup();
+        if buckets.len() > 1 {
+            let version_list = versions
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", ");
+            issues.push(Issue {
+                category: "lockfile_drift".to_string(),
+                severity: "warning".to_string(),
+                message: format!(
+                    "'{name}' resolves to incompatible versions in Cargo.lock: {version_list}"
+                ),
+                path: Some(lock_path.to_path_buf()),
+                line: None,
+                suggestion: None,
+            });
+        }
+    }
+}
+
+/// Mirrors Cargo's caret-requirement compatibility rule so that `0.x`
+/// releases, where the minor version is the breaking component, aren't
+/// treated as compatible just because the major version matches.
+fn compat_bucket(v: &Version) -> (u64, u64, u64) {
+    if v.major > 0 {
+        (v.major, 0, 0)
+    } else if v.minor > 0 {
+        (0, v.minor, 0)
+    } else {
+        (0, 0, v.patch)
+    }
+}
+
+const DEPENDENCY_TABLE_NAMES: &[&str] = &["dependencies", "dev-dependencies", "build-dependencies"];
+
+/// Flags dependencies declared in `Cargo.toml` that never made it into
+/// `Cargo.lock` (a stale lock). Covers both the top-level dependency tables
+/// and every `[target.'cfg(...)'.dependencies]` table, and resolves a
+/// dependency's `package = "..."` rename so a renamed crate is compared
+/// against its real name rather than its local alias.
+fn check_missing_from_lock(
+    manifest_path: &Path,
+    lock_packages: &[toml::Value],
+    lock_path: &Path,
+    issues: &mut Vec<Issue>,
+) {
+    let Ok(content) = fs::read_to_string(manifest_path) else {
+        return;
+    };
+    let Ok(doc) = content.parse::<toml::Value>() else {
+        return;
+    };
+
+    let lock_names: HashSet<&str> = lock_packages
+        .iter()
+        .filter_map(|p| p.get("name").and_then(|n| n.as_str()))
+        .collect();
+
+    let mut dep_tables: Vec<&toml::value::Table> = Vec::new();
+    for table_name in DEPENDENCY_TABLE_NAMES {
+        if let Some(table) = doc.get(table_name).and_then(|d| d.as_table()) {
+            dep_tables.push(table);
+        }
+    }
+    if let Some(targets) = doc.get("target").and_then(|t| t.as_table()) {
+        for target_spec in targets.values() {
+            for table_name in DEPENDENCY_TABLE_NAMES {
+                if let Some(table) = target_spec.get(table_name).and_then(|d| d.as_table()) {
+                    dep_tables.push(table);
+                }
+            }
+        }
+    }
+
+    for table in dep_tables {
+        for (alias, spec) in table {
+            let real_name = spec
+                .get("package")
+                .and_then(|p| p.as_str())
+                .unwrap_or(alias.as_str());
+            if !lock_names.contains(real_name) {
+                issues.push(Issue {
+                    category: "lockfile_drift".to_string(),
+                    severity: "warning".to_string(),
+                    message: format!(
+                        "Dependency '{alias}' is declared in Cargo.toml but missing from Cargo.lock"
+                    ),
+                    path: Some(lock_path.to_path_buf()),
+                    line: None,
+                    suggestion: None,
+                });
+            }
+        }
+    }
+}
+
+/// Flags a `Cargo.lock` that's older than `Cargo.toml`, a cheap signal that
+/// it hasn't been regenerated since the manifest last changed.
+fn check_lock_mtime(manifest_path: &Path, lock_path: &Path, issues: &mut Vec<Issue>) {
+    let (Ok(manifest_meta), Ok(lock_meta)) = (fs::metadata(manifest_path), fs::metadata(lock_path))
+    else {
+        return;
+    };
+    let (Ok(manifest_mtime), Ok(lock_mtime)) = (manifest_meta.modified(), lock_meta.modified())
+    else {
+        return;
+    };
+
+    if lock_mtime < manifest_mtime {
+        issues.push(Issue {
+            category: "lockfile_drift".to_string(),
+            severity: "info".to_string(),
+            message: "Cargo.lock is older than Cargo.toml; dependencies may be out of date"
+                .to_string(),
+            path: Some(PathBuf::from(lock_path)),
+            line: None,
+            suggestion: None,
+        });
+    }
+}
@@ -2,7 +2,7 @@ use clap::Parser;
 use std::path::PathBuf;
 use std::process::ExitCode;
 
-use drift::{run_audit, Issue};
+use drift::{apply_fixes, run_audit, Issue};
 
 #[derive(Parser)]
 #[command(name = "drift")]
@@ -15,6 +15,14 @@ struct Cli {
     #[arg(short, long)]
     json: bool,
 
+    /// Automatically apply self-healing suggestions instead of just previewing them
+    #[arg(long)]
+    fix: bool,
+
+    /// Path to a drift.toml config file (defaults to discovering one in the audited directory)
+    #[arg(long)]
+    config: Option<PathBuf>,
+
     /// Directory to audit (defaults to current directory)
     #[arg(default_value = ".")]
     path: PathBuf,
@@ -23,14 +31,31 @@ struct Cli {
 fn main() -> ExitCode {
     let cli = Cli::parse();
 
+    // Resolve --config against the original working directory before we
+    // chdir into the audited path, so a relative path still points where
+    // the user meant it to.
+    let config_path = cli.config.as_ref().map(|p| {
+        std::env::current_dir()
+            .map(|cwd| cwd.join(p))
+            .unwrap_or_else(|_| p.clone())
+    });
+
     if let Err(e) = std::env::set_current_dir(&cli.path) {
         eprintln!("Error: Cannot access directory {:?}: {}", cli.path, e);
         return ExitCode::FAILURE;
     }
 
-    let issues = run_audit();
+    let issues = run_audit(config_path.as_deref());
 
-    if cli.json {
+    if cli.fix {
+        match apply_fixes(&issues) {
+            Ok(changed) => print_fix_summary(&changed),
+            Err(e) => {
+                eprintln!("Error: Failed to apply fixes: {}", e);
+                return ExitCode::FAILURE;
+            }
+        }
+    } else if cli.json {
         println!("{}", serde_json::to_string_pretty(&issues).unwrap());
     } else {
         print_human_readable(&issues);
@@ -52,6 +77,7 @@ fn print_human_readable(issues: &[Issue]) {
     println!("Drift Audit Results");
     println!("===================\n");
 
+    let mut fixable = 0;
     for issue in issues {
         let icon = match issue.severity.as_str() {
             "error" => "✗",
@@ -66,10 +92,33 @@ fn print_human_readable(issues: &[Issue]) {
         if let Some(line) = issue.line {
             print!(":{}", line);
         }
+        if issue.suggestion.is_some() {
+            fixable += 1;
+            print!(" [fixable]");
+        }
         println!();
     }
 
     let errors = issues.iter().filter(|i| i.severity == "error").count();
     let warnings = issues.iter().filter(|i| i.severity == "warning").count();
     println!("\nSummary: {} errors, {} warnings", errors, warnings);
+
+    if fixable > 0 {
+        println!(
+            "{} issue(s) can be auto-fixed; re-run with --fix to apply",
+            fixable
+        );
+    }
+}
+
+fn print_fix_summary(changed: &[PathBuf]) {
+    if changed.is_empty() {
+        println!("✓ Nothing to fix");
+        return;
+    }
+
+    println!("Applied fixes to {} file(s):", changed.len());
+    for path in changed {
+        println!("  {}", path.display());
+    }
 }
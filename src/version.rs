@@ -0,0 +1,169 @@
+//! Semver-aware toolchain and language-version consistency checks.
+//!
+//! The previous implementation used brittle `String::contains` checks (e.g.
+//! substring-matching an `.nvmrc` version inside `package.json`), which both
+//! missed real mismatches and flagged nightly toolchains that never actually
+//! conflicted with a pinned `rust-version`. This module parses each manifest
+//! properly with `semver` and only emits an issue when a pinned version
+//! genuinely falls outside a declared requirement.
+
+use semver::{Version, VersionReq};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::{Config, Issue};
+
+/// Takes `_config` for consistency with the other `check_*` entry points;
+/// `drift.toml` currently has no version-specific settings, but severities
+/// and whether this check runs at all are already applied centrally in
+/// `run_audit`.
+pub fn check_version_mismatches(root: &Path, _config: &Config) -> Vec<Issue> {
+    let mut issues = Vec::new();
+
+    check_toolchain_vs_rust_version(root, &mut issues);
+    check_node_version(root, &mut issues);
+    check_workspace_rust_version_consistency(root, &mut issues);
+
+    issues
+}
+
+/// Compares the pinned `rust-toolchain.toml` version against the minimum
+/// `rust-version` declared in `Cargo.toml`, when both resolve to a concrete
+/// version (a `"nightly"`/`"stable"`/`"beta"` channel has nothing to compare
+/// against and is silently skipped).
+fn check_toolchain_vs_rust_version(root: &Path, issues: &mut Vec<Issue>) {
+    let Some(toolchain_version) = read_toolchain_version(root) else {
+        return;
+    };
+    let Some(min_version) = read_cargo_rust_version(&root.join("Cargo.toml")) else {
+        return;
+    };
+    let Ok(req) = VersionReq::parse(&format!(">={min_version}")) else {
+        return;
+    };
+
+    if !req.matches(&toolchain_version) {
+        issues.push(Issue {
+            category: "version_mismatch".to_string(),
+            severity: "error".to_string(),
+            message: format!(
+                "rust-toolchain.toml pins {toolchain_version} but Cargo.toml requires rust-version {min_version}"
+            ),
+            path: Some(PathBuf::from("rust-toolchain.toml")),
+            line: None,
+            suggestion: None,
+        });
+    }
+}
+
+fn read_toolchain_version(root: &Path) -> Option<Version> {
+    let content = fs::read_to_string(root.join("rust-toolchain.toml")).ok()?;
+    let doc: toml::Value = content.parse().ok()?;
+    let channel = doc.get("toolchain")?.get("channel")?.as_str()?;
+    parse_loose_version(channel)
+}
+
+fn read_cargo_rust_version(manifest_path: &Path) -> Option<Version> {
+    let content = fs::read_to_string(manifest_path).ok()?;
+    let doc: toml::Value = content.parse().ok()?;
+    let raw = doc.get("package")?.get("rust-version")?.as_str()?;
+    parse_loose_version(raw)
+}
+
+/// Version strings in these manifests are often bare (`"1.75"`), so pad them
+/// out to full semver before handing them to the `semver` crate.
+fn parse_loose_version(raw: &str) -> Option<Version> {
+    let raw = raw.trim().trim_start_matches('v');
+    match raw.matches('.').count() {
+        0 => Version::parse(&format!("{raw}.0.0")).ok(),
+        1 => Version::parse(&format!("{raw}.0")).ok(),
+        _ => Version::parse(raw).ok(),
+    }
+}
+
+/// Compares `.nvmrc` against `package.json`'s `engines.node` requirement.
+fn check_node_version(root: &Path, issues: &mut Vec<Issue>) {
+    let Ok(nvmrc) = fs::read_to_string(root.join(".nvmrc")) else {
+        return;
+    };
+    let Some(nvmrc_version) = parse_loose_version(nvmrc.trim()) else {
+        return;
+    };
+
+    let Ok(pkg_content) = fs::read_to_string(root.join("package.json")) else {
+        return;
+    };
+    let Ok(pkg) = serde_json::from_str::<serde_json::Value>(&pkg_content) else {
+        return;
+    };
+    let Some(engines_node) = pkg
+        .get("engines")
+        .and_then(|e| e.get("node"))
+        .and_then(|n| n.as_str())
+    else {
+        return;
+    };
+    let Ok(req) = VersionReq::parse(engines_node) else {
+        return;
+    };
+
+    if !req.matches(&nvmrc_version) {
+        issues.push(Issue {
+            category: "version_mismatch".to_string(),
+            severity: "warning".to_string(),
+            message: format!(
+                ".nvmrc pins node {nvmrc_version} but package.json requires engines.node {engines_node}"
+            ),
+            path: Some(PathBuf::from(".nvmrc")),
+            line: None,
+            suggestion: None,
+        });
+    }
+}
+
+/// Flags Cargo workspaces whose member manifests declare divergent
+/// `rust-version` values.
+fn check_workspace_rust_version_consistency(root: &Path, issues: &mut Vec<Issue>) {
+    let content = match fs::read_to_string(root.join("Cargo.toml")) {
+        Ok(content) => content,
+        Err(_) => return,
+    };
+    let Ok(doc) = content.parse::<toml::Value>() else {
+        return;
+    };
+    let Some(members) = doc
+        .get("workspace")
+        .and_then(|w| w.get("members"))
+        .and_then(|m| m.as_array())
+    else {
+        return;
+    };
+
+    let versions: Vec<(String, Version)> = members
+        .iter()
+        .filter_map(|m| m.as_str())
+        .filter_map(|member| {
+            read_cargo_rust_version(&root.join(member).join("Cargo.toml"))
+                .map(|v| (member.to_string(), v))
+        })
+        .collect();
+
+    let Some((_, baseline)) = versions.first() else {
+        return;
+    };
+
+    for (member, version) in &versions[1..] {
+        if version != baseline {
+            issues.push(Issue {
+                category: "version_mismatch".to_string(),
+                severity: "warning".to_string(),
+                message: format!(
+                    "Workspace member '{member}' declares rust-version {version}, which diverges from {baseline}"
+                ),
+                path: Some(PathBuf::from(member).join("Cargo.toml")),
+                line: None,
+                suggestion: None,
+            });
+        }
+    }
+}
@@ -0,0 +1,155 @@
+//! Shared filesystem traversal for all per-file checks.
+//!
+//! Historically `check_stale_configs` and `check_dead_code_markers` each
+//! recursed the tree themselves with hand-rolled skip lists, which meant the
+//! tree was walked twice and neither walk respected `.gitignore`/`.ignore`
+//! rules (so ignored backup files were still flagged). This module walks the
+//! tree exactly once with `ignore::WalkBuilder` - the same approach rustc's
+//! `tidy` tool uses - and dispatches every per-file check from a single
+//! parallel visitor. A `.driftignore` file is honored alongside the standard
+//! ignore files.
+
+use ignore::{WalkBuilder, WalkState};
+use std::path::Path;
+use std::sync::Mutex;
+
+use crate::{fix, Config, Issue};
+
+/// Walks `root` once in parallel and runs every per-file check against each
+/// visited file, honoring `.gitignore`, `.ignore`, and `.driftignore`.
+pub fn run_file_checks(root: &Path, config: &Config) -> Vec<Issue> {
+    let issues = Mutex::new(Vec::new());
+    let check_stale = config.is_check_enabled("stale_config");
+    let check_dead_code = config.is_check_enabled("dead_code");
+
+    let walker = WalkBuilder::new(root)
+        .add_custom_ignore_filename(".driftignore")
+        .build_parallel();
+
+    walker.run(|| {
+        Box::new(|entry| {
+            if let Ok(entry) = entry {
+                if entry.file_type().is_some_and(|t| t.is_file()) {
+                    if check_stale {
+                        check_stale_extension(entry.path(), config, &issues);
+                    }
+                    if check_dead_code {
+                        check_dead_code_markers(entry.path(), config, &issues);
+                    }
+                }
+            }
+            WalkState::Continue
+        })
+    });
+
+    issues.into_inner().unwrap_or_default()
+}
+
+/// Flags backup/stale files by extension (e.g. `.old`, `.bak`).
+fn check_stale_extension(path: &Path, config: &Config, issues: &Mutex<Vec<Issue>>) {
+    if let Some(ext) = path.extension().and_then(|e| e.to_str()) {
+        if config.stale_extensions.iter().any(|s| s == ext) {
+            issues.lock().unwrap().push(Issue {
+                category: "stale_config".to_string(),
+                severity: "warning".to_string(),
+                message: "Stale configuration or backup file".to_string(),
+                path: Some(path.to_path_buf()),
+                line: None,
+                suggestion: Some(fix::delete_file(path.to_path_buf())),
+            });
+        }
+    }
+}
+
+/// Line-comment leaders recognized when deciding whether a marker's line is
+/// safe to delete outright - see [`is_comment_only_line`]. Among the scanned
+/// languages only Python starts a comment with `#`; elsewhere a leading `#`
+/// begins real code (a Rust attribute, a C preprocessor directive).
+fn line_comment_leaders(ext: &str) -> &'static [&'static str] {
+    if ext == "py" {
+        &["#"]
+    } else {
+        &["//"]
+    }
+}
+
+/// Scans source files for dead-code markers like `TODO`/`FIXME`. A marker
+/// explicitly tagged resolved (e.g. `TODO(done): ...`) gets a [`fix::Suggestion`]
+/// that strips the whole line, but only when the marker's line is nothing but
+/// a comment; a marker trailing real code (e.g. `do_thing(); // TODO(done):
+/// cleanup`) never gets a whole-line suggestion, since deleting the line
+/// would also delete the code. A bare, unresolved marker never gets a
+/// suggestion either way, since there's no way to tell whether the
+/// underlying work is actually finished.
+fn check_dead_code_markers(path: &Path, config: &Config, issues: &Mutex<Vec<Issue>>) {
+    let ext = path.extension().and_then(|e| e.to_str()).unwrap_or("");
+    if !config.source_extensions.iter().any(|s| s == ext) {
+        return;
+    }
+
+    let Ok(content) = std::fs::read_to_string(path) else {
+        return;
+    };
+
+    let mut found = Vec::new();
+    let mut offset = 0;
+    for (line_num, raw_line) in content.split_inclusive('\n').enumerate() {
+        let line = raw_line.trim_end_matches('\n');
+        let line_range = offset..offset + raw_line.len();
+        offset += raw_line.len();
+
+        for marker in &config.dead_code_markers {
+            let Some(marker_pos) = line.find(marker.as_str()) else {
+                continue;
+            };
+            let resolved = is_marked_resolved(&line[marker_pos + marker.len()..])
+                && is_comment_only_line(line, marker_pos, ext);
+            found.push(Issue {
+                category: "dead_code".to_string(),
+                severity: "info".to_string(),
+                message: format!("{} marker found", marker),
+                path: Some(path.to_path_buf()),
+                line: Some(line_num + 1),
+                suggestion: resolved.then(|| fix::Suggestion {
+                    path: path.to_path_buf(),
+                    byte_range: line_range.clone(),
+                    replacement: String::new(),
+                }),
+            });
+        }
+    }
+
+    if !found.is_empty() {
+        issues.lock().unwrap().extend(found);
+    }
+}
+
+/// Whether `line` contains nothing but whitespace before a line-comment
+/// leader of the file's language (by extension `ext`) that starts at or
+/// before `marker_pos` - i.e. the marker isn't trailing real code, so the
+/// whole line is safe to delete.
+fn is_comment_only_line(line: &str, marker_pos: usize, ext: &str) -> bool {
+    let prefix = &line[..marker_pos];
+    line_comment_leaders(ext).iter().any(|leader| {
+        prefix
+            .find(leader)
+            .is_some_and(|leader_pos| prefix[..leader_pos].trim().is_empty())
+    })
+}
+
+/// A marker is treated as resolved - and therefore safe to strip with
+/// `--fix` - only when it's explicitly tagged `(done)`, `(fixed)`, or
+/// `(resolved)` right after the marker word, e.g. `// TODO(done): cleanup`.
+fn is_marked_resolved(after_marker: &str) -> bool {
+    let Some(inner) = after_marker
+        .trim_start()
+        .strip_prefix('(')
+        .and_then(|s| s.split(')').next())
+    else {
+        return false;
+    };
+    matches!(
+        inner.trim().to_ascii_lowercase().as_str(),
+        "done" | "fixed" | "resolved"
+    )
+}